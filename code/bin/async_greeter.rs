@@ -0,0 +1,94 @@
+use core::future::Future;
+use core::pin::pin;
+use core::ptr;
+use core::task::{Context as TaskContext, Poll, RawWaker, RawWakerVTable, Waker};
+
+use cgp::prelude::*; // Import all CGP constructs
+
+// Derive CGP provider traits and blanket implementations.
+// Async components need no special syntax: writing `async fn` in the
+// consumer trait makes the generated provider trait `Greeter<Context>`
+// and its blanket impl forward the `async` automatically. There is no
+// `Send`/`?Send` knob to configure — the returned future's `Send`-ness
+// just falls out of whatever the provider body captures.
+#[cgp_component(Greeter)]
+pub trait CanGreet // Name of the consumer trait
+{
+    async fn greet(&self);
+}
+
+// A getter trait representing a dependency for `name` value
+#[cgp_auto_getter] // Derive blanket implementation
+pub trait HasName {
+    fn name(&self) -> &str;
+}
+
+// Implement `Greeter` that is generic over `Context`. The provider
+// method is `async fn` and may `.await` I/O, e.g. a grpc `Greeter`
+// handler replying to a request.
+#[cgp_new_provider]
+impl<Context> Greeter<Context> for GreetHello
+where
+    Context: HasName, // Inject the `name` dependency from `Context`
+{
+    async fn greet(context: &Context) {
+        println!("Hello, {}!", context.name());
+    }
+}
+
+// A concrete context that uses CGP components
+#[cgp_context]
+#[derive(HasField)] // Deriving `HasField` automatically implements `HasName`
+pub struct Person {
+    pub name: String,
+}
+
+// Compile-time wiring of CGP components
+delegate_components! {
+    PersonComponents {
+        GreeterComponent: GreetHello, // Use `GreetHello` to provide `Greeter`
+    }
+}
+
+fn main() {
+    let person = Person {
+        name: "Alice".into(),
+    };
+
+    // `CanGreet` is automatically implemented for `Person`. Drive the
+    // future to completion with a tiny dependency-free executor.
+    block_on(person.greet());
+
+    // Construct the future on this thread, then move the future itself
+    // (not just a reference) across to another OS thread to drive it —
+    // only typechecks because the future `greet()` returns is `Send`.
+    let greeting = person.greet();
+    std::thread::scope(|scope| {
+        scope.spawn(move || block_on(greeting));
+    });
+}
+
+// A minimal `block_on` so the example stays free of executor
+// dependencies; real service contexts would hand the future to
+// `tokio`/`async-std` instead.
+fn block_on<F: Future>(future: F) -> F::Output {
+    fn raw_waker() -> RawWaker {
+        fn no_op(_: *const ()) {}
+        fn clone(_: *const ()) -> RawWaker {
+            raw_waker()
+        }
+
+        let vtable = &RawWakerVTable::new(clone, no_op, no_op, no_op);
+        RawWaker::new(ptr::null(), vtable)
+    }
+
+    let waker = unsafe { Waker::from_raw(raw_waker()) };
+    let mut cx = TaskContext::from_waker(&waker);
+    let mut future = pin!(future);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use core::fmt::Display;
+
+use cgp::prelude::*; // Import all CGP constructs
+
+// Derive CGP provider traits and blanket implementations
+#[cgp_component(Greeter)]
+pub trait CanGreet {
+    fn greet(&self);
+}
+
+// A CGP abstract type `Name`
+#[cgp_type]
+pub trait HasNameType {
+    type Name;
+}
+
+// A getter trait representing a dependency for `name` value
+#[cgp_auto_getter]
+pub trait HasName: HasNameType {
+    fn name(&self) -> &Self::Name;
+}
+
+// `GreetHello` requires `Context: HasName` (and a `Display` name), which
+// in turn requires `NameTypeProviderComponent` to be wired.
+#[cgp_new_provider]
+impl<Context> Greeter<Context> for GreetHello
+where
+    Context: HasName,
+    Context::Name: Display,
+{
+    fn greet(context: &Context) {
+        println!("Hello, {}!", context.name());
+    }
+}
+
+// A concrete context that uses CGP components
+#[cgp_context]
+#[derive(HasField)]
+pub struct Person {
+    pub name: String,
+}
+
+// Compile-time wiring of CGP components.
+delegate_components! {
+    PersonComponents {
+        NameTypeProviderComponent:
+            UseType<String>,
+        GreeterComponent:
+            GreetHello,
+    }
+}
+
+// Assert at compile time that `Person` wires each hand-listed
+// component with its consumer trait. A mismatch fails at the named
+// component instead of deep inside `HasComponents`/`DelegateComponent`
+// resolution; it does not discover the list itself from `GreetHello`'s
+// bounds, so a component added here later needs its own line.
+check_components! {
+    CanUsePerson for Person {
+        NameTypeProviderComponent: HasNameType,
+        GreeterComponent: CanGreet,
+    }
+}
+
+fn main() {
+    let person = Person {
+        name: "Alice".into(),
+    };
+
+    person.greet();
+}
@@ -0,0 +1,91 @@
+use cgp::prelude::*; // Import all CGP constructs
+
+// Derive CGP provider traits and blanket implementations
+#[cgp_component(Greeter)]
+pub trait CanGreet // Name of the consumer trait
+{
+    fn greet(&self);
+}
+
+// A getter trait representing a dependency for `name` value. Declared
+// with an explicit `provider` so it is a delegated component, not just
+// a blanket impl.
+#[cgp_getter {
+    provider: NameGetter,
+}]
+pub trait HasName {
+    fn name(&self) -> &str;
+}
+
+// The production provider, used by the real wiring.
+#[cgp_new_provider]
+impl<Context> Greeter<Context> for GreetHello
+where
+    Context: HasName,
+{
+    fn greet(context: &Context) {
+        println!("Hello, {}!", context.name());
+    }
+}
+
+// A mock provider, swapped in for `GreetHello` by `MockPersonComponents`.
+#[cgp_new_provider]
+impl<Context> Greeter<Context> for GreetSilently {
+    fn greet(_context: &Context) {
+        // Say nothing: calling `greet` through this provider produces no
+        // visible output.
+    }
+}
+
+// A concrete context that uses CGP components
+#[cgp_context]
+#[derive(HasField)]
+pub struct Person {
+    pub name: String,
+}
+
+// The real, production wiring of CGP components.
+delegate_components! {
+    PersonComponents {
+        NameGetterComponent: UseFields,
+        GreeterComponent: GreetHello,
+    }
+}
+
+// A test wiring that swaps in `GreetSilently`. CGP has no
+// `override_components!`/inheritance: this is a second, independent
+// `delegate_components!` block, so `NameGetterComponent` has to be
+// re-listed even though it is unchanged from `PersonComponents`.
+pub struct MockPersonComponents;
+
+delegate_components! {
+    MockPersonComponents {
+        NameGetterComponent: UseFields,
+        GreeterComponent: GreetSilently,
+    }
+}
+
+#[derive(HasField)]
+pub struct MockPerson {
+    pub name: String,
+}
+
+impl HasComponents for MockPerson {
+    type Components = MockPersonComponents;
+}
+
+fn main() {
+    let person = Person {
+        name: "Alice".into(),
+    };
+
+    // Uses the real `GreetHello` provider.
+    person.greet();
+
+    let mock = MockPerson {
+        name: "Alice".into(),
+    };
+
+    // Uses the overridden `GreetSilently` provider.
+    mock.greet();
+}
@@ -0,0 +1,85 @@
+use core::cell::OnceCell;
+use core::marker::PhantomData;
+
+use cgp::prelude::*; // Import all CGP constructs
+
+// A CGP abstract type `Name`
+#[cgp_type]
+pub trait HasNameType {
+    type Name;
+}
+
+// A getter trait representing a dependency for `name` value.
+// Declared with an explicit `provider`, so the macro generates a
+// `NameGetterComponent`/`NameGetter` provider that a custom provider can
+// implement — rather than `#[cgp_auto_getter]`, which would emit only a
+// direct blanket impl that reads the field verbatim.
+#[cgp_getter {
+    provider: NameGetter,
+}]
+pub trait HasName: HasNameType {
+    fn name(&self) -> &Self::Name;
+}
+
+// A getter for the raw first/last name fields that the constructor
+// provider reads in order to build the derived `name` value.
+#[cgp_auto_getter]
+pub trait HasNameParts {
+    fn first_name(&self) -> &str;
+    fn last_name(&self) -> &str;
+}
+
+// A `NameGetter` provider whose value is *computed* from other injected
+// dependencies instead of being read verbatim from a field. CGP getters
+// return `&Self::Name`, so the value is memoized into the context's
+// `OnceCell<Name>` field (discovered via `HasField`): it is built on the
+// first `name()` call and the cached borrow is returned thereafter, so
+// construction runs at most once. This provider is hand-written, not a
+// reusable kind, and has no guard against `name()` calling itself
+// transitively — that would reenter `get_or_init` and panic.
+#[cgp_new_provider]
+impl<Context> NameGetter<Context> for BuildName
+where
+    Context: HasNameType<Name = String>
+        + HasNameParts
+        + HasField<symbol!("name"), Value = OnceCell<String>>,
+{
+    fn name(context: &Context) -> &String {
+        context
+            .get_field(PhantomData)
+            .get_or_init(|| format!("{} {}", context.first_name(), context.last_name()))
+    }
+}
+
+// A concrete context that uses CGP components. The `name` field is a
+// `OnceCell` so the derived value can be initialized on first access.
+#[cgp_context]
+#[derive(HasField)]
+pub struct Person {
+    pub first_name: String,
+    pub last_name: String,
+    pub name: OnceCell<String>,
+}
+
+// Compile-time wiring of CGP components
+delegate_components! {
+    PersonComponents {
+        NameTypeProviderComponent:
+            UseType<String>,
+        NameGetterComponent:
+            BuildName, // Compute and memoize `name` from the other fields
+    }
+}
+
+fn main() {
+    let person = Person {
+        first_name: "Alice".into(),
+        last_name: "Anderson".into(),
+        name: OnceCell::new(),
+    };
+
+    // The first call runs `BuildName` and caches the result; the second
+    // call returns the same borrow without recomputing.
+    println!("Hello, {}!", person.name());
+    println!("Hello again, {}!", person.name());
+}